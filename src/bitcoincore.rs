@@ -1,5 +1,7 @@
 use base64;
+use bitcoin::address::NetworkUnchecked;
 use bitcoin::Address;
+use bitcoin::Network;
 use bitcoin::Script;
 use jsonrpc_client::{
     header::{HeaderMap, HeaderValue, AUTHORIZATION},
@@ -7,24 +9,282 @@ use jsonrpc_client::{
 };
 use rpc;
 use serde::{de::DeserializeOwned, ser::Serialize};
+use std::cell::RefCell;
 use std::fmt::Debug;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use types::address::AddressInfoResult;
 use BitcoinRpcApi;
 use BlockHash;
 use TransactionId;
 
-struct RetryConfig {
+/// -28: node is still warming up (e.g. replaying the block index). This is
+/// the canonical "come back in a bit" error.
+const RPC_IN_WARMUP: i64 = -28;
+
+/// Retries only `RPC_IN_WARMUP`. In particular this does *not* retry -27
+/// ("transaction already in block chain", also returned for transactions
+/// already accepted into the mempool): blindly retrying it never helps,
+/// since the transaction either already confirmed or is already known to
+/// the node, so doing so just wastes time and can mask an idempotent
+/// success as a hang.
+fn default_is_retryable(error: &RpcError) -> bool {
+    error.code == RPC_IN_WARMUP
+}
+
+/// Whether a transport-level failure is worth retrying: a node that briefly
+/// refused the connection or timed out while still starting up, not a
+/// permanent failure such as bad credentials or a response that doesn't
+/// match the type we asked for. Retrying the latter would just spin for
+/// `max_retries` attempts before surfacing an error it could have returned
+/// immediately.
+fn is_transient(error: &ClientError) -> bool {
+    error.is_timeout() || error.is_connect()
+}
+
+/// Builds a [`RetryPolicy`]. By default it retries RPC errors only on "still
+/// starting up" (plus transient transport failures), up to 10 retries,
+/// starting half a second apart and doubling each time up to 30 seconds,
+/// without jitter.
+pub struct RetryPolicyBuilder {
     max_retries: u32,
-    interval: u64,
+    base_interval: Duration,
+    multiplier: f64,
+    max_interval: Duration,
+    jitter: bool,
+    is_retryable: Box<dyn Fn(&RpcError) -> bool>,
 }
 
-pub struct BitcoinCoreClient {
-    client: RpcClient,
-    retry_config: Option<RetryConfig>,
+impl Default for RetryPolicyBuilder {
+    fn default() -> Self {
+        RetryPolicyBuilder {
+            max_retries: 10,
+            base_interval: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_interval: Duration::from_secs(30),
+            jitter: false,
+            is_retryable: Box::new(default_is_retryable),
+        }
+    }
 }
 
 #[allow(dead_code)]
-impl BitcoinCoreClient {
+impl RetryPolicyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn base_interval(mut self, base_interval: Duration) -> Self {
+        self.base_interval = base_interval;
+        self
+    }
+
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Overrides which RPC errors are considered worth retrying. The
+    /// default retries `-28` (node warming up) and nothing else; in
+    /// particular it does not retry `-27` ("transaction already in block
+    /// chain"), since that is an idempotent-success case, not a failure.
+    pub fn retryable_if<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&RpcError) -> bool + 'static,
+    {
+        self.is_retryable = Box::new(predicate);
+        self
+    }
+
+    pub fn build(self) -> RetryPolicy {
+        RetryPolicy {
+            max_retries: self.max_retries,
+            base_interval: self.base_interval,
+            multiplier: self.multiplier,
+            max_interval: self.max_interval,
+            jitter: self.jitter,
+            is_retryable: self.is_retryable,
+        }
+    }
+}
+
+/// Exponential-backoff retry policy covering both RPC-level errors (judged
+/// by a caller-supplied predicate) and transient transport failures such as
+/// a connection refused while the node is still binding its RPC port.
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_interval: Duration,
+    multiplier: f64,
+    max_interval: Duration,
+    jitter: bool,
+    is_retryable: Box<dyn Fn(&RpcError) -> bool>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicyBuilder::default().build()
+    }
+}
+
+impl RetryPolicy {
+    fn next_interval(&self, current: Duration) -> Duration {
+        let scaled = current.mul_f64(self.multiplier);
+        if scaled > self.max_interval {
+            self.max_interval
+        } else {
+            scaled
+        }
+    }
+
+    /// Applies jitter (if enabled) so that several clients backing off at
+    /// the same time don't all retry in lock-step.
+    fn sleep_duration(&self, interval: Duration) -> Duration {
+        if !self.jitter {
+            return interval;
+        }
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos())
+            .unwrap_or(0);
+        let jitter_fraction = f64::from(nanos % 1000) / 1000.0;
+
+        interval.mul_f64(0.5 + jitter_fraction * 0.5)
+    }
+}
+
+/// Abstracts the transport a [`BitcoinCoreClient`] sends requests over.
+/// The production path goes over `RpcClient` (a real HTTP connection); tests
+/// can plug in [`mock::MockSender`](::mock::MockSender) instead to drive
+/// `BitcoinRpcApi` calls against canned responses, without a node.
+pub trait Sender {
+    fn send<R: DeserializeOwned + Debug, P: Serialize + Debug>(
+        &self,
+        request: &RpcRequest<P>,
+    ) -> Result<Result<R, RpcError>, ClientError>;
+}
+
+impl Sender for RpcClient {
+    fn send<R: DeserializeOwned + Debug, P: Serialize + Debug>(
+        &self,
+        request: &RpcRequest<P>,
+    ) -> Result<Result<R, RpcError>, ClientError> {
+        RpcClient::send(self, request)
+    }
+}
+
+/// A base64-encoded Partially Signed Bitcoin Transaction, as produced and
+/// consumed by the `*psbt*` RPCs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Psbt(pub String);
+
+#[derive(Debug, Deserialize)]
+pub struct WalletCreateFundedPsbtResult {
+    pub psbt: Psbt,
+    pub fee: f64,
+    #[serde(rename = "changepos")]
+    pub change_position: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WalletProcessPsbtResult {
+    pub psbt: Psbt,
+    pub complete: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FinalizePsbtResult {
+    pub psbt: Option<Psbt>,
+    pub hex: Option<rpc::SerializedRawTransaction>,
+    pub complete: bool,
+}
+
+/// The fee estimation mode `estimatesmartfee` should target, mirroring
+/// Bitcoin Core's `estimate_mode` RPC argument.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub enum EstimateMode {
+    #[serde(rename = "UNSET")]
+    Unset,
+    #[serde(rename = "ECONOMICAL")]
+    Economical,
+    #[serde(rename = "CONSERVATIVE")]
+    Conservative,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeeEstimate {
+    /// The estimated fee rate in BTC/kvB, absent if the node could not
+    /// produce an estimate for `blocks`.
+    #[serde(rename = "feerate")]
+    pub fee_rate: Option<f64>,
+    /// The confirmation target actually used, which may differ from the
+    /// one requested.
+    pub blocks: u32,
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MempoolInfo {
+    #[serde(rename = "mempoolminfee")]
+    pub mempool_min_fee: f64,
+    #[serde(rename = "minrelaytxfee")]
+    pub min_relay_tx_fee: f64,
+}
+
+/// Why [`BitcoinCoreClient::wait_for_confirmations`] gave up before the
+/// transaction reached the requested depth.
+#[derive(Debug)]
+pub enum ConfirmationError {
+    /// A poll of the node failed at the client level (e.g. the connection
+    /// failed, or the response couldn't be read).
+    Client(ClientError),
+    /// `timeout` elapsed before the transaction reached `target`
+    /// confirmations.
+    TimedOut,
+    /// The transaction reached `target` confirmations, but looking up the
+    /// block it confirmed in (to report its hash and height) failed. Unlike
+    /// `TimedOut`, the transaction did confirm — only the follow-up lookup
+    /// didn't succeed.
+    ConfirmedButBlockLookupFailed,
+}
+
+/// The error surfaced by methods that take an address or hand back one the
+/// node returned: either the node reported an RPC-level error, or the
+/// address doesn't belong to the network this client is configured for.
+#[derive(Debug)]
+pub enum AddressNetworkError {
+    Rpc(RpcError),
+    /// The address belongs to a different network than the one this
+    /// client's node runs on (e.g. a regtest address from a mainnet node).
+    WrongNetwork,
+    /// `getblockchaininfo` reported a `chain` value this client doesn't
+    /// recognise (e.g. a newer Core release adding a chain we don't know
+    /// about yet, such as "testnet4").
+    UnknownChain(String),
+}
+
+pub struct BitcoinCoreClient<S: Sender = RpcClient> {
+    client: S,
+    retry_policy: Option<RetryPolicy>,
+    network: RefCell<Option<Network>>,
+}
+
+impl BitcoinCoreClient<RpcClient> {
     pub fn new(url: &str, username: &str, password: &str) -> Self {
         let mut headers = HeaderMap::new();
         headers.insert(
@@ -45,13 +305,95 @@ impl BitcoinCoreClient {
 
         BitcoinCoreClient {
             client: rpc_client,
-            retry_config: Some(RetryConfig {
-                max_retries: 10,
-                interval: 500,
-            }),
+            retry_policy: Some(RetryPolicy::default()),
+            network: RefCell::new(None),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<S: Sender> BitcoinCoreClient<S> {
+    /// Builds a client around an arbitrary [`Sender`], bypassing the HTTP
+    /// setup `new` does. Used by [`mock::mock_client`](::mock::mock_client)
+    /// to wire up a `MockSender`.
+    pub fn from_sender(sender: S) -> Self {
+        BitcoinCoreClient {
+            client: sender,
+            retry_policy: Some(RetryPolicy::default()),
+            network: RefCell::new(None),
+        }
+    }
+
+    /// Pins the network this client validates addresses against, instead of
+    /// discovering it lazily from `getblockchaininfo` on first use.
+    pub fn with_network(self, network: Network) -> Self {
+        *self.network.borrow_mut() = Some(network);
+        self
+    }
+
+    /// The network the connected node runs on, fetching and caching it via
+    /// `getblockchaininfo` the first time it's needed if it wasn't supplied
+    /// up front with [`with_network`](Self::with_network). Returns
+    /// [`AddressNetworkError::UnknownChain`] rather than panicking if the
+    /// node reports a `chain` value this client doesn't recognise yet.
+    fn network(&self) -> Result<Result<Network, AddressNetworkError>, ClientError> {
+        if let Some(network) = *self.network.borrow() {
+            return Ok(Ok(network));
+        }
+
+        let info = match self.get_blockchain_info()? {
+            Ok(info) => info,
+            Err(rpc_error) => return Ok(Err(AddressNetworkError::Rpc(rpc_error))),
+        };
+
+        let network = match info.chain.as_str() {
+            "main" => Network::Bitcoin,
+            "test" => Network::Testnet,
+            "regtest" => Network::Regtest,
+            "signet" => Network::Signet,
+            other => return Ok(Err(AddressNetworkError::UnknownChain(other.to_owned()))),
+        };
+
+        *self.network.borrow_mut() = Some(network);
+
+        Ok(Ok(network))
+    }
+
+    /// Checks that `address` belongs to this client's network before it is
+    /// sent to the node, catching cross-network mistakes (e.g. passing a
+    /// regtest address to a mainnet node) at the client boundary rather
+    /// than relying on the node to reject it.
+    fn check_network(
+        &self,
+        address: &Address,
+    ) -> Result<Result<(), AddressNetworkError>, ClientError> {
+        match self.network()? {
+            Ok(network) if address.is_valid_for_network(network) => Ok(Ok(())),
+            Ok(_) => Ok(Err(AddressNetworkError::WrongNetwork)),
+            Err(error) => Ok(Err(error)),
         }
     }
 
+    /// Replaces the client's retry policy. See [`RetryPolicyBuilder`] to
+    /// configure backoff and which errors should be retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Disables retrying altogether; every request is attempted exactly
+    /// once.
+    pub fn without_retry(mut self) -> Self {
+        self.retry_policy = None;
+        self
+    }
+
+    /// The underlying transport. Mainly useful in tests, to inspect the
+    /// requests a [`mock::MockSender`](::mock::MockSender) captured.
+    pub fn sender(&self) -> &S {
+        &self.client
+    }
+
     fn get_raw_transaction<R: Debug>(
         &self,
         tx: &TransactionId,
@@ -68,9 +410,72 @@ impl BitcoinCoreClient {
             verbose,
         ))
     }
+
+    /// Polls the node until `txid` reaches `target` confirmations, using
+    /// the same backoff as the retry policy, or returns
+    /// [`ConfirmationError::TimedOut`] once `timeout` elapses. A
+    /// transaction that isn't known to the node yet (e.g. it hasn't
+    /// propagated to the mempool), or that simply hasn't reached `target`
+    /// confirmations, is treated as "keep waiting" rather than a hard
+    /// error, since that's the expected state right after broadcasting.
+    ///
+    /// `confirmations` is absent from the node's response until the
+    /// transaction is actually mined, so it's read as `Option<u32>` rather
+    /// than required — treating a transaction still sitting in the mempool
+    /// the same as one with zero confirmations, instead of a
+    /// deserialization failure.
+    pub fn wait_for_confirmations(
+        &self,
+        txid: &TransactionId,
+        target: u32,
+        timeout: Duration,
+    ) -> Result<(BlockHash, rpc::BlockHeight), ConfirmationError> {
+        let backoff = self.retry_policy.as_ref();
+        let mut interval = backoff
+            .map(|policy| policy.base_interval)
+            .unwrap_or_else(|| Duration::from_millis(500));
+        let deadline = SystemTime::now() + timeout;
+
+        loop {
+            match self.get_raw_transaction_verbose(txid) {
+                Err(client_error) => return Err(ConfirmationError::Client(client_error)),
+                Ok(Ok(ref tx)) if tx.confirmations.unwrap_or(0) >= target => {
+                    return match tx.block_hash {
+                        Some(ref block_hash) => match self.get_block(block_hash) {
+                            Ok(Ok(block)) => Ok((block_hash.clone(), block.height)),
+                            _ => Err(ConfirmationError::ConfirmedButBlockLookupFailed),
+                        },
+                        None => Err(ConfirmationError::ConfirmedButBlockLookupFailed),
+                    };
+                }
+                // Either not enough confirmations yet, or the node doesn't
+                // know about the transaction at all (still propagating) —
+                // both mean "keep waiting" until `timeout` elapses.
+                Ok(_) => {}
+            }
+
+            let remaining = match deadline.duration_since(SystemTime::now()) {
+                Ok(remaining) => remaining,
+                Err(_) => return Err(ConfirmationError::TimedOut),
+            };
+
+            let sleep_for = backoff
+                .map(|policy| policy.sleep_duration(interval))
+                .unwrap_or(interval);
+
+            // Never sleep past `deadline`: the backoff interval grows up to
+            // `max_interval`, which would otherwise let a single sleep
+            // overshoot a short `timeout` by a wide margin.
+            ::std::thread::sleep(::std::cmp::min(sleep_for, remaining));
+
+            interval = backoff
+                .map(|policy| policy.next_interval(interval))
+                .unwrap_or(interval);
+        }
+    }
 }
 
-impl BitcoinRpcApi for BitcoinCoreClient {
+impl<S: Sender> BitcoinRpcApi for BitcoinCoreClient<S> {
     // Order as per: https://bitcoin.org/en/developer-reference#rpcs
 
     fn add_multisig_address(
@@ -87,6 +492,15 @@ impl BitcoinRpcApi for BitcoinCoreClient {
         ))
     }
 
+    fn combine_psbt(&self, psbts: Vec<&Psbt>) -> Result<Result<Psbt, RpcError>, ClientError> {
+        self.send(&RpcRequest::new1(
+            JsonRpcVersion::V1,
+            "42",
+            "combinepsbt",
+            psbts,
+        ))
+    }
+
     fn create_raw_transaction(
         &self,
         inputs: Vec<&rpc::NewTransactionInput>,
@@ -128,12 +542,43 @@ impl BitcoinRpcApi for BitcoinCoreClient {
     fn dump_privkey(
         &self,
         address: &Address,
-    ) -> Result<Result<rpc::PrivateKey, RpcError>, ClientError> {
-        self.send(&RpcRequest::new1(
+    ) -> Result<Result<rpc::PrivateKey, AddressNetworkError>, ClientError> {
+        if let Err(error) = self.check_network(address)? {
+            return Ok(Err(error));
+        }
+
+        let result: Result<Result<rpc::PrivateKey, RpcError>, ClientError> = self.send(
+            &RpcRequest::new1(JsonRpcVersion::V1, "42", "dumpprivkey", address),
+        );
+
+        result.map(|inner| inner.map_err(AddressNetworkError::Rpc))
+    }
+
+    fn estimate_smart_fee(
+        &self,
+        conf_target: u32,
+        mode: Option<EstimateMode>,
+    ) -> Result<Result<FeeEstimate, RpcError>, ClientError> {
+        self.send(&RpcRequest::new2(
             JsonRpcVersion::V1,
             "42",
-            "dumpprivkey",
-            address,
+            "estimatesmartfee",
+            conf_target,
+            mode,
+        ))
+    }
+
+    fn finalize_psbt(
+        &self,
+        psbt: &Psbt,
+        extract: Option<bool>,
+    ) -> Result<Result<FinalizePsbtResult, RpcError>, ClientError> {
+        self.send(&RpcRequest::new2(
+            JsonRpcVersion::V1,
+            "42",
+            "finalizepsbt",
+            psbt,
+            extract,
         ))
     }
 
@@ -180,13 +625,16 @@ impl BitcoinRpcApi for BitcoinCoreClient {
     fn get_address_info(
         &self,
         address: &Address,
-    ) -> Result<Result<AddressInfoResult, RpcError>, ClientError> {
-        self.send(&RpcRequest::new1(
-            JsonRpcVersion::V1,
-            "42",
-            "getaddressinfo",
-            address,
-        ))
+    ) -> Result<Result<AddressInfoResult, AddressNetworkError>, ClientError> {
+        if let Err(error) = self.check_network(address)? {
+            return Ok(Err(error));
+        }
+
+        let result: Result<Result<AddressInfoResult, RpcError>, ClientError> = self.send(
+            &RpcRequest::new1(JsonRpcVersion::V1, "42", "getaddressinfo", address),
+        );
+
+        result.map(|inner| inner.map_err(AddressNetworkError::Rpc))
     }
 
     fn get_balance(&self) -> Result<Result<f32, RpcError>, ClientError> {
@@ -247,16 +695,39 @@ impl BitcoinRpcApi for BitcoinCoreClient {
         ))
     }
 
-    fn get_new_address(&self) -> Result<Result<Address, RpcError>, ClientError> {
-        self.send(&RpcRequest::new2(
+    fn get_mempool_info(&self) -> Result<Result<MempoolInfo, RpcError>, ClientError> {
+        self.send(&RpcRequest::new0(
             JsonRpcVersion::V1,
             "42",
-            "getnewaddress",
-            "",
-            "bech32",
+            "getmempoolinfo",
         ))
     }
 
+    fn get_new_address(&self) -> Result<Result<Address, AddressNetworkError>, ClientError> {
+        let unchecked: Result<Result<Address<NetworkUnchecked>, RpcError>, ClientError> = self
+            .send(&RpcRequest::new2(
+                JsonRpcVersion::V1,
+                "42",
+                "getnewaddress",
+                "",
+                "bech32",
+            ));
+
+        let address = match unchecked? {
+            Ok(address) => address,
+            Err(rpc_error) => return Ok(Err(AddressNetworkError::Rpc(rpc_error))),
+        };
+
+        let network = match self.network()? {
+            Ok(network) => network,
+            Err(error) => return Ok(Err(error)),
+        };
+
+        Ok(address
+            .require_network(network)
+            .map_err(|_| AddressNetworkError::WrongNetwork))
+    }
+
     fn get_raw_transaction_serialized(
         &self,
         tx: &TransactionId,
@@ -310,14 +781,21 @@ impl BitcoinRpcApi for BitcoinCoreClient {
         &self,
         address: &Address,
         amount: f64,
-    ) -> Result<Result<TransactionId, RpcError>, ClientError> {
-        self.send(&RpcRequest::new2(
-            JsonRpcVersion::V1,
-            "42",
-            "sendtoaddress",
-            address,
-            amount,
-        ))
+    ) -> Result<Result<TransactionId, AddressNetworkError>, ClientError> {
+        if let Err(error) = self.check_network(address)? {
+            return Ok(Err(error));
+        }
+
+        let result: Result<Result<TransactionId, RpcError>, ClientError> =
+            self.send(&RpcRequest::new2(
+                JsonRpcVersion::V1,
+                "42",
+                "sendtoaddress",
+                address,
+                amount,
+            ));
+
+        result.map(|inner| inner.map_err(AddressNetworkError::Rpc))
     }
 
     fn sign_raw_transaction_with_key(
@@ -349,28 +827,78 @@ impl BitcoinRpcApi for BitcoinCoreClient {
             address,
         ))
     }
+
+    fn wallet_create_funded_psbt(
+        &self,
+        inputs: Vec<&rpc::NewTransactionInput>,
+        output: &rpc::NewTransactionOutput,
+        locktime: Option<u32>,
+        options: Option<&rpc::FundingOptions>,
+    ) -> Result<Result<WalletCreateFundedPsbtResult, RpcError>, ClientError> {
+        self.send(&RpcRequest::new4(
+            JsonRpcVersion::V1,
+            "42",
+            "walletcreatefundedpsbt",
+            inputs,
+            output,
+            locktime,
+            options,
+        ))
+    }
+
+    fn wallet_process_psbt(
+        &self,
+        psbt: &Psbt,
+        sign: Option<bool>,
+    ) -> Result<Result<WalletProcessPsbtResult, RpcError>, ClientError> {
+        self.send(&RpcRequest::new2(
+            JsonRpcVersion::V1,
+            "42",
+            "walletprocesspsbt",
+            psbt,
+            sign,
+        ))
+    }
 }
 
-impl BitcoinCoreClient {
+impl<S: Sender> BitcoinCoreClient<S> {
     fn send<R: DeserializeOwned + Debug, P: Serialize + Debug>(
         &self,
         request: &RpcRequest<P>,
     ) -> Result<Result<R, RpcError>, ClientError> {
-        if let Some(ref config) = self.retry_config {
-            for i in 0..config.max_retries {
-                let result = self.client.send::<R, P>(request);
-
-                match result {
-                    Ok(Err(ref rpc_error)) if rpc_error.code == -28 => {
-                        info!("Bitcoind is still starting up. Request will be retried in {} milliseconds. ({}/{}) ", config.interval, i, config.max_retries);
-
-                        ::std::thread::sleep(::std::time::Duration::from_millis(config.interval));
-                        continue;
-                    }
-                    _ => return result,
-                }
+        let policy = match self.retry_policy {
+            Some(ref policy) => policy,
+            None => return self.client.send(request),
+        };
+
+        let mut interval = policy.base_interval;
+
+        for attempt in 0..policy.max_retries {
+            let result = Sender::send::<R, P>(&self.client, request);
+
+            let should_retry = match result {
+                Ok(Err(ref rpc_error)) => (policy.is_retryable)(rpc_error),
+                Err(ref client_error) => is_transient(client_error),
+                Ok(Ok(_)) => false,
+            };
+
+            if !should_retry {
+                return result;
             }
+
+            let sleep_for = policy.sleep_duration(interval);
+            info!(
+                "Request failed, will be retried in {:?} ({}/{}): {:?}",
+                sleep_for,
+                attempt + 1,
+                policy.max_retries,
+                result
+            );
+
+            ::std::thread::sleep(sleep_for);
+            interval = policy.next_interval(interval);
         }
+
         self.client.send(request)
     }
 }
@@ -0,0 +1,126 @@
+use bitcoincore::{BitcoinCoreClient, Sender};
+use jsonrpc_client::{ClientError, RpcError, RpcRequest};
+use serde::{de::DeserializeOwned, ser::Serialize};
+use serde_json;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+
+/// A client wired up to a [`MockSender`] instead of a real node, for testing
+/// `BitcoinRpcApi` callers offline.
+pub type MockBitcoinCoreClient = BitcoinCoreClient<MockSender>;
+
+/// Builds a `MockBitcoinCoreClient` around `sender`. Use
+/// `MockSender::new().respond_with(...)` to stage canned responses first.
+pub fn mock_client(sender: MockSender) -> MockBitcoinCoreClient {
+    BitcoinCoreClient::from_sender(sender).without_retry()
+}
+
+/// One staged reply for a method: either the `result` a real node would
+/// return, or the `error` object it would return in its place. Kept as
+/// [`serde_json::Value`] rather than eagerly deserializing into `R`/
+/// [`RpcError`], since the target type `R` isn't known until a
+/// `BitcoinRpcApi` call actually asks for it.
+#[derive(Clone)]
+enum MockResponse {
+    Result(serde_json::Value),
+    RpcError(serde_json::Value),
+}
+
+/// A [`Sender`] that, instead of talking to a node, returns canned JSON-RPC
+/// responses registered per method name and records every request it was
+/// handed, so a test can assert on the exact wire format (method, params,
+/// JSON-RPC version) a `BitcoinRpcApi` call produced, or exercise how a
+/// caller reacts to an RPC-level error.
+pub struct MockSender {
+    responses: RefCell<HashMap<&'static str, VecDeque<MockResponse>>>,
+    requests: RefCell<Vec<serde_json::Value>>,
+}
+
+impl MockSender {
+    pub fn new() -> Self {
+        MockSender {
+            responses: RefCell::new(HashMap::new()),
+            requests: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn stage(self, method: &'static str, response: MockResponse) -> Self {
+        self.responses
+            .borrow_mut()
+            .entry(method)
+            .or_insert_with(VecDeque::new)
+            .push_back(response);
+        self
+    }
+
+    /// Registers the `result` to return the next time `method` is called.
+    /// Calling this more than once for the same `method` queues up a
+    /// sequence of distinct responses, one per call, with the last one
+    /// repeating for any call beyond the registered sequence — handy for
+    /// exercising a polling loop that only confirms after a few attempts.
+    pub fn respond_with(self, method: &'static str, result: serde_json::Value) -> Self {
+        self.stage(method, MockResponse::Result(result))
+    }
+
+    /// Registers an RPC-level error (the `{"code": ..., "message": ...}`
+    /// object a node returns alongside a `null` result) to return the next
+    /// time `method` is called. Lets a test exercise a caller's error- and
+    /// retry-handling offline — e.g. staging `RPC_IN_WARMUP` (-28) followed
+    /// by a success to confirm `send`'s retry policy recovers from it, or a
+    /// permanent error to confirm it doesn't.
+    pub fn respond_with_rpc_error(self, method: &'static str, code: i64, message: &str) -> Self {
+        let mut error = serde_json::Map::new();
+        error.insert("code".to_owned(), serde_json::Value::from(code));
+        error.insert("message".to_owned(), serde_json::Value::from(message));
+
+        self.stage(method, MockResponse::RpcError(serde_json::Value::Object(error)))
+    }
+
+    /// The raw JSON-RPC requests sent so far, in the order they were sent.
+    pub fn requests(&self) -> Vec<serde_json::Value> {
+        self.requests.borrow().clone()
+    }
+}
+
+impl Sender for MockSender {
+    fn send<R: DeserializeOwned + Debug, P: Serialize + Debug>(
+        &self,
+        request: &RpcRequest<P>,
+    ) -> Result<Result<R, RpcError>, ClientError> {
+        let request_json = serde_json::to_value(request).expect("RpcRequest always serializes");
+        let method = request_json["method"]
+            .as_str()
+            .expect("RpcRequest always has a method")
+            .to_owned();
+
+        self.requests.borrow_mut().push(request_json);
+
+        let mut responses = self.responses.borrow_mut();
+        let queue = responses
+            .get_mut(method.as_str())
+            .unwrap_or_else(|| panic!("MockSender: no response registered for `{}`", method));
+        let response = if queue.len() > 1 {
+            queue.pop_front().expect("just checked len() > 1")
+        } else {
+            queue
+                .front()
+                .expect("MockSender: no response registered for this method")
+                .clone()
+        };
+
+        match response {
+            MockResponse::Result(value) => {
+                let result = serde_json::from_value(value).expect(
+                    "MockSender: registered response does not match the requested result type",
+                );
+                Ok(Ok(result))
+            }
+            MockResponse::RpcError(value) => {
+                let error = serde_json::from_value(value)
+                    .expect("MockSender: staged RPC error value does not deserialize into RpcError");
+                Ok(Err(error))
+            }
+        }
+    }
+}
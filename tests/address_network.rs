@@ -0,0 +1,50 @@
+extern crate bitcoin;
+extern crate bitcoin_rpc_client;
+#[macro_use]
+extern crate serde_json;
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::Address;
+use bitcoin_rpc_client::mock::{mock_client, MockSender};
+use bitcoin_rpc_client::{AddressNetworkError, BitcoinRpcApi};
+
+fn testnet_address() -> Address {
+    "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+        .parse::<Address<NetworkUnchecked>>()
+        .unwrap()
+        .assume_checked()
+}
+
+#[test]
+fn rejects_an_address_from_a_different_network_than_the_node() {
+    let client = mock_client(
+        MockSender::new().respond_with("getblockchaininfo", json!({ "chain": "main" })),
+    );
+
+    let error = client
+        .dump_privkey(&testnet_address())
+        .unwrap()
+        .unwrap_err();
+
+    match error {
+        AddressNetworkError::WrongNetwork => {}
+        other => panic!("expected WrongNetwork, got {:?}", other),
+    }
+}
+
+#[test]
+fn surfaces_an_unrecognised_chain_as_a_typed_error_instead_of_panicking() {
+    let client = mock_client(
+        MockSender::new().respond_with("getblockchaininfo", json!({ "chain": "testnet4" })),
+    );
+
+    let error = client
+        .dump_privkey(&testnet_address())
+        .unwrap()
+        .unwrap_err();
+
+    match error {
+        AddressNetworkError::UnknownChain(chain) => assert_eq!(chain, "testnet4"),
+        other => panic!("expected UnknownChain, got {:?}", other),
+    }
+}
@@ -1,14 +1,14 @@
 use bitcoin_rpc_client::BitcoinCoreClient;
 use coblox_bitcoincore::BitcoinCore;
 use jsonrpc_client::HTTPError;
-use jsonrpc_client::RpcError;
 use std::fmt::Debug;
 use testcontainers::{clients::DockerCli, Docker};
 
-pub fn assert_successful_result<R, I>(invocation: I)
+pub fn assert_successful_result<R, E, I>(invocation: I)
 where
     R: Debug,
-    I: Fn(&BitcoinCoreClient) -> Result<Result<R, RpcError>, HTTPError>,
+    E: Debug,
+    I: Fn(&BitcoinCoreClient) -> Result<Result<R, E>, HTTPError>,
 {
     let container = DockerCli::new().run(BitcoinCore::default());
     let client = {
@@ -28,9 +28,12 @@ where
             // - No deserialization error occured
             debug!("Returned result: {:?}", result)
         }
-        Ok(Err(rpc_error)) => panic!(
-            "Network call was successful but node returned rpc-error: {:?}",
-            rpc_error
+        // `E` covers both the plain `RpcError` most calls return and the
+        // richer `AddressNetworkError` the address-aware calls (e.g.
+        // `dump_privkey`, `get_new_address`) return instead.
+        Ok(Err(error)) => panic!(
+            "Network call was successful but node returned an error: {:?}",
+            error
         ),
         Err(http_error) => panic!("Failed to connect to node: {:?}", http_error),
     }
@@ -0,0 +1,76 @@
+extern crate bitcoin;
+extern crate bitcoin_rpc_client;
+#[macro_use]
+extern crate serde_json;
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::Address;
+use bitcoin_rpc_client::mock::{mock_client, MockSender};
+use bitcoin_rpc_client::{AddressNetworkError, BitcoinRpcApi, RetryPolicyBuilder};
+use std::time::Duration;
+
+fn testnet_address() -> Address {
+    "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+        .parse::<Address<NetworkUnchecked>>()
+        .unwrap()
+        .assume_checked()
+}
+
+#[test]
+fn retries_a_warming_up_node_and_recovers() {
+    let client = mock_client(
+        MockSender::new()
+            .respond_with_rpc_error("getbalance", -28, "Loading block index...")
+            .respond_with("getbalance", json!(1.5)),
+    )
+    .with_retry_policy(
+        RetryPolicyBuilder::new()
+            .max_retries(3)
+            .base_interval(Duration::from_millis(1))
+            .build(),
+    );
+
+    let balance = client.get_balance().unwrap().unwrap();
+
+    assert_eq!(balance, 1.5);
+    assert_eq!(client.sender().requests().len(), 2);
+}
+
+#[test]
+fn does_not_retry_a_permanent_rpc_error() {
+    let client = mock_client(MockSender::new().respond_with_rpc_error(
+        "getbalance",
+        -1,
+        "Something went permanently wrong",
+    ))
+    .with_retry_policy(
+        RetryPolicyBuilder::new()
+            .max_retries(3)
+            .base_interval(Duration::from_millis(1))
+            .build(),
+    );
+
+    let error = client.get_balance().unwrap().unwrap_err();
+
+    assert_eq!(error.code, -1);
+    assert_eq!(client.sender().requests().len(), 1);
+}
+
+#[test]
+fn surfaces_an_rpc_error_from_the_network_lookup() {
+    let client = mock_client(MockSender::new().respond_with_rpc_error(
+        "getblockchaininfo",
+        -1,
+        "Something went permanently wrong",
+    ));
+
+    let error = client
+        .dump_privkey(&testnet_address())
+        .unwrap()
+        .unwrap_err();
+
+    match error {
+        AddressNetworkError::Rpc(rpc_error) => assert_eq!(rpc_error.code, -1),
+        other => panic!("expected Rpc, got {:?}", other),
+    }
+}
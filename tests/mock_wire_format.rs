@@ -0,0 +1,41 @@
+extern crate bitcoin_rpc_client;
+#[macro_use]
+extern crate serde_json;
+
+use bitcoin_rpc_client::mock::{mock_client, MockSender};
+use bitcoin_rpc_client::BitcoinRpcApi;
+
+#[test]
+fn get_balance_sends_no_params() {
+    let client = mock_client(MockSender::new().respond_with("getbalance", json!(1.5)));
+
+    let balance = client.get_balance().unwrap().unwrap();
+
+    assert_eq!(balance, 1.5);
+    assert_eq!(
+        client.sender().requests(),
+        vec![json!({
+            "jsonrpc": "1.0",
+            "id": "42",
+            "method": "getbalance",
+            "params": []
+        })]
+    );
+}
+
+#[test]
+fn generate_sends_the_block_count_as_its_only_param() {
+    let client = mock_client(MockSender::new().respond_with("generate", json!([])));
+
+    client.generate(101).unwrap().unwrap();
+
+    assert_eq!(
+        client.sender().requests(),
+        vec![json!({
+            "jsonrpc": "1.0",
+            "id": "42",
+            "method": "generate",
+            "params": [101]
+        })]
+    );
+}
@@ -0,0 +1,35 @@
+extern crate bitcoin_rpc_client;
+#[macro_use]
+extern crate serde_json;
+
+use bitcoin_rpc_client::mock::{mock_client, MockSender};
+use std::time::Duration;
+
+#[test]
+fn waits_while_transaction_is_still_unconfirmed_in_the_mempool() {
+    let txid = "1111111111111111111111111111111111111111111111111111111111111111";
+    let block_hash = "2222222222222222222222222222222222222222222222222222222222222222";
+
+    let client = mock_client(
+        MockSender::new()
+            // No `confirmations` field at all, as bitcoind reports for a
+            // transaction that's only reached the mempool so far.
+            .respond_with("getrawtransaction", json!({ "txid": txid }))
+            .respond_with(
+                "getrawtransaction",
+                json!({
+                    "txid": txid,
+                    "confirmations": 1,
+                    "blockhash": block_hash
+                }),
+            )
+            .respond_with("getblock", json!({ "hash": block_hash, "height": 101 })),
+    );
+
+    let (confirmed_in, height) = client
+        .wait_for_confirmations(&txid.parse().unwrap(), 1, Duration::from_secs(5))
+        .unwrap();
+
+    assert_eq!(confirmed_in, block_hash.parse().unwrap());
+    assert_eq!(height, 101);
+}